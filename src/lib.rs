@@ -29,8 +29,34 @@
 //!     println!("{}", pow_mod(2, 10_000_000, 1_000_000).start_recursion());
 //! }
 //! ```
+//!
+//! When a function recurses into more than one child at a time, use
+//! `recurse_join` instead of awaiting several `recurse()` calls one by
+//! one; it schedules every child onto the heap stack at once and
+//! resolves to a `Vec` of their outputs once all of them are done. On
+//! this single-threaded path the branches still run to completion one
+//! at a time (in reverse registration order), not interleaved — it just
+//! saves you from awaiting them individually. For branches that actually
+//! run concurrently, enable the `parallel` feature and use
+//! [`parallel::ParallelPool::recurse_join`] instead.
+//!
+//! `start_recursion` drives a thread-local default [`Recursion`]; hot
+//! loops that run many recursions back to back can instead build their
+//! own `Recursion::with_capacity` and call [`Recursion::run`] repeatedly
+//! to reuse its stack across runs.
+//!
+//! `start_recursion` blocks the calling thread until the recursion is
+//! done. To embed a heap recursion inside an existing async runtime
+//! instead, call `into_recursion_future()` to get back a plain `Future`
+//! that advances the recursion a bit on every poll and yields to the
+//! outer executor in between.
+//!
+//! With the `parallel` feature enabled, the [`parallel`] module offers a
+//! [`parallel::ParallelPool`] that runs the independent branches of
+//! `recurse_join` across a small work-stealing thread pool, for `Send`
+//! futures whose output is also `Send`.
 
-use std::{cell::RefCell, pin::Pin, rc::Rc};
+use std::{any::Any, cell::RefCell, pin::Pin, rc::Rc};
 
 pub struct Output<T> {
     state: Rc<RefCell<Option<T>>>,
@@ -94,8 +120,81 @@ where
     }
 }
 
+/// A boxed recursion frame that can also be downcast back to its
+/// concrete `FutureWrapper<F>`, so a completed frame's allocation can be
+/// handed back for reuse by a later frame of the same `F`.
+trait AnyFuture: Future<Output = ()> {
+    fn as_any_ref(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+impl<Fut> AnyFuture for Fut
+where
+    Fut: Future<Output = ()> + 'static,
+{
+    fn as_any_ref(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Box `f` as a recursion frame, reusing a free-listed allocation of the
+/// same concrete type if one is available instead of allocating anew.
+fn alloc_frame<F>(f: F) -> Pin<Box<dyn AnyFuture>>
+where
+    F: Future<Output = ()> + 'static,
+{
+    let reused = FREE_LIST.with(|list| {
+        let mut list = list.borrow_mut();
+        list.iter()
+            .position(|slot| slot.as_any_ref().is::<F>())
+            .map(|index| list.swap_remove(index))
+    });
+    match reused {
+        Some(mut boxed) => {
+            *boxed.as_any_mut().downcast_mut::<F>().unwrap() = f;
+            unsafe { Pin::new_unchecked(boxed) }
+        }
+        None => Box::pin(f),
+    }
+}
+
+/// Return a finished frame's allocation to the free list instead of
+/// dropping it, so the next frame of the same concrete type can reuse it.
+fn recycle_frame(frame: Pin<Box<dyn AnyFuture>>) {
+    let boxed = unsafe { Pin::into_inner_unchecked(frame) };
+    FREE_LIST.with(|list| list.borrow_mut().push(boxed));
+}
+
 thread_local! {
-    static RECURSION_TEM: RefCell<Option<Pin<Box<dyn Future<Output = ()>>>>> = const { RefCell::new(None) };
+    static RECURSION_TEM: RefCell<Vec<Pin<Box<dyn AnyFuture>>>> = const { RefCell::new(Vec::new()) };
+    static FREE_LIST: RefCell<Vec<Box<dyn AnyFuture>>> = const { RefCell::new(Vec::new()) };
+    static DEFAULT_RECURSION: RefCell<Recursion> = const { RefCell::new(Recursion::new()) };
+}
+
+pub struct Join<T> {
+    states: Vec<Rc<RefCell<Option<T>>>>,
+}
+impl<T: Unpin> Future for Join<T> {
+    type Output = Vec<T>;
+    fn poll(
+        self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let states = &self.get_mut().states;
+        if states.iter().all(|state| state.borrow().is_some()) {
+            std::task::Poll::Ready(
+                states
+                    .iter()
+                    .map(|state| state.borrow_mut().take().unwrap())
+                    .collect(),
+            )
+        }
+        else {
+            std::task::Poll::Pending
+        }
+    }
 }
 
 pub trait FutureRecursion
@@ -104,60 +203,848 @@ where
 {
     fn start_recursion(self) -> Self::Output;
     fn recurse(self) -> Output<Self::Output>;
+    fn into_recursion_future(self) -> RecursionFuture<Self::Output>;
 }
 
-mod noop_waker {
-    unsafe fn noop_clone(_data: *const ()) -> std::task::RawWaker {
-        noop_raw_waker()
-    }
-    unsafe fn noop(_data: *const ()) {}
-    const NOOP_WAKER_VTABLE: std::task::RawWakerVTable =
-        std::task::RawWakerVTable::new(noop_clone, noop, noop, noop);
-    const fn noop_raw_waker() -> std::task::RawWaker {
-        std::task::RawWaker::new(std::ptr::null(), &NOOP_WAKER_VTABLE)
+/// The number of stack pops/pushes a [`RecursionFuture`] advances by on
+/// each outer poll before yielding back to the outer executor.
+const DEFAULT_STEP_BUDGET: usize = 1024;
+
+/// A heap recursion that is itself a [`Future`], so it can be driven
+/// cooperatively by an outer executor (e.g. `tokio::select!`, a timeout,
+/// or cancellation) instead of blocking the calling thread to completion.
+///
+/// Each [`poll`](Future::poll) advances the recursion by a bounded budget
+/// of steps and returns `Poll::Pending` (after re-arming the outer waker)
+/// if the budget runs out with work remaining, so an unbounded recursion
+/// never monopolizes the outer
+/// executor. The outer `Context`'s waker is handed straight down to every
+/// child future, so a recursive branch that awaits genuine async work
+/// still wakes the outer task correctly.
+pub struct RecursionFuture<T> {
+    stack: Vec<Pin<Box<dyn AnyFuture>>>,
+    output: Output<T>,
+    budget: usize,
+}
+impl<T> RecursionFuture<T> {
+    /// Override the number of steps advanced per outer poll.
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget = budget;
+        self
     }
-    #[inline]
-    pub fn noop_waker() -> std::task::Waker {
-        unsafe { std::task::Waker::from_raw(noop_raw_waker()) }
+}
+impl<T: Unpin> Future for RecursionFuture<T> {
+    type Output = T;
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let this = self.get_mut();
+
+        for _ in 0..this.budget {
+            let Some(frame) = this.stack.last_mut()
+            else {
+                break;
+            };
+            match frame.as_mut().poll(cx) {
+                std::task::Poll::Ready(_) => {
+                    if let Some(done) = this.stack.pop() {
+                        recycle_frame(done);
+                    }
+                }
+                std::task::Poll::Pending => {
+                    let children =
+                        RECURSION_TEM.with(|tem| std::mem::take(&mut *tem.borrow_mut()));
+                    if children.is_empty() {
+                        // A genuine suspension: the outer waker was
+                        // already handed to the child future above, so
+                        // the outer runtime will re-poll us once it fires.
+                        return std::task::Poll::Pending;
+                    }
+                    this.stack.extend(children);
+                }
+            }
+        }
+
+        if this.stack.is_empty() {
+            std::task::Poll::Ready(this.output.state.borrow_mut().take().unwrap())
+        }
+        else {
+            // Budget exhausted but work remains: yield cooperatively.
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
     }
 }
 
-impl<F> FutureRecursion for F
+/// Schedule several child futures onto the heap stack at once and await
+/// all of their results together, instead of one `recurse()` at a time.
+///
+/// This only saves you from awaiting each branch individually: the
+/// branches still run to completion one at a time (in reverse
+/// registration order) on the shared heap stack, not interleaved. Use
+/// [`parallel::ParallelPool::recurse_join`] if you need them to actually
+/// run concurrently.
+pub fn recurse_join<F, I>(futures: I) -> Join<F::Output>
 where
     F: Future + 'static,
     F::Output: Unpin,
+    I: IntoIterator<Item = F>,
 {
-    fn start_recursion(self) -> Self::Output {
-        let tem = RECURSION_TEM.replace(None);
+    let states = futures
+        .into_iter()
+        .map(|f| {
+            let (fw, output) = FutureWrapper::new(f);
+            RECURSION_TEM.with(|tem| tem.borrow_mut().push(alloc_frame(fw)));
+            output.state
+        })
+        .collect();
+    Join { states }
+}
+
+/// A reusable recursion runner.
+///
+/// Like the thread-local default that [`FutureRecursion::start_recursion`]
+/// drives, a `Recursion` keeps its stack allocated across calls to
+/// [`Recursion::run`], and [`Recursion::with_capacity`] preallocates it,
+/// so repeatedly running recursions on the same `Recursion` amortizes the
+/// `Vec` growth that a single very deep recursion would otherwise pay once.
+/// Individual recursion frames are also recycled through a free list shared
+/// with the free-standing `recurse`/`recurse_join` functions, so a hot loop
+/// of same-shaped recursions mostly reuses existing allocations instead of
+/// calling the global allocator on every descent.
+pub struct Recursion {
+    stack: Vec<Pin<Box<dyn AnyFuture>>>,
+}
+impl Recursion {
+    pub const fn new() -> Self {
+        Self { stack: Vec::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            stack: Vec::with_capacity(capacity),
+        }
+    }
 
-        let waker = noop_waker::noop_waker();
+    pub fn run<Fut>(&mut self, fut: Fut) -> Fut::Output
+    where
+        Fut: Future + 'static,
+        Fut::Output: Unpin,
+    {
+        let tem = RECURSION_TEM.with(|tem| tem.replace(Vec::new()));
+
+        let (park_waker, waker) = park_waker::park_waker();
         let mut context = std::task::Context::from_waker(&waker);
-        let mut stack: Vec<Pin<Box<dyn Future<Output = ()>>>> = vec![];
 
-        let (f, output) = FutureWrapper::new(self);
-        stack.push(Box::pin(f));
-        while let Some(l) = stack.last_mut() {
+        let (f, output) = FutureWrapper::new(fut);
+        self.stack.push(alloc_frame(f));
+        while let Some(l) = self.stack.last_mut() {
             match l.as_mut().poll(&mut context) {
                 std::task::Poll::Ready(_) => {
-                    stack.pop();
+                    if let Some(done) = self.stack.pop() {
+                        recycle_frame(done);
+                    }
                 }
                 std::task::Poll::Pending => {
-                    if let Some(f) = RECURSION_TEM.replace(None) {
-                        stack.push(f);
+                    let children =
+                        RECURSION_TEM.with(|tem| std::mem::take(&mut *tem.borrow_mut()));
+                    if children.is_empty() {
+                        // A genuine suspension (timer, channel, I/O, ...):
+                        // block until the waker wakes this thread back up,
+                        // then re-poll the same future.
+                        park_waker.park();
+                    }
+                    else {
+                        // One or more recursive calls were just scheduled:
+                        // descend into all of them.
+                        self.stack.extend(children);
                     }
                 }
             }
         }
 
-        RECURSION_TEM.set(tem);
+        RECURSION_TEM.with(|t| t.replace(tem));
 
         output.state.take().unwrap()
     }
+}
+impl Default for Recursion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+mod park_waker {
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        task::{Wake, Waker},
+        thread::{self, Thread},
+    };
+
+    /// A waker that parks the current thread and unparks it when woken,
+    /// so the executor can genuinely block on real async work instead of
+    /// busy-spinning.
+    pub struct ParkWaker {
+        unparked: AtomicBool,
+        thread: Thread,
+    }
+
+    impl ParkWaker {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                unparked: AtomicBool::new(false),
+                thread: thread::current(),
+            })
+        }
+
+        /// Block until this waker is woken, then clear the flag.
+        pub fn park(&self) {
+            while !self.unparked.swap(false, Ordering::Acquire) {
+                thread::park();
+            }
+        }
+    }
+
+    impl Wake for ParkWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.unparked.store(true, Ordering::Release);
+            self.thread.unpark();
+        }
+    }
+
+    pub fn park_waker() -> (Arc<ParkWaker>, Waker) {
+        let park_waker = ParkWaker::new();
+        let waker = Waker::from(park_waker.clone());
+        (park_waker, waker)
+    }
+}
+
+/// Optional parallel evaluation of the independent branches scheduled by
+/// `recurse_join`, on a small work-stealing thread pool.
+///
+/// Everything in this module requires the `parallel` feature, and only
+/// supports `Send` futures whose output is `Send`; the single-threaded
+/// path in the crate root stays the default for everything else.
+#[cfg(feature = "parallel")]
+pub mod parallel {
+    use std::{
+        collections::VecDeque,
+        future::Future,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicU8, Ordering},
+            Arc, Condvar, Mutex,
+        },
+        task::{Context, Poll, Wake, Waker},
+        thread,
+        time::Duration,
+    };
+
+    /// The `Arc`/`Mutex` twin of [`crate::Output`], for a child future
+    /// that may resolve on a different worker thread than the one
+    /// polling the handle.
+    pub struct SharedOutput<T> {
+        state: Arc<Mutex<Option<T>>>,
+    }
+    impl<T> Default for SharedOutput<T> {
+        fn default() -> Self {
+            Self {
+                state: Arc::new(Mutex::new(None)),
+            }
+        }
+    }
+    impl<T: Send + Unpin> Future for SharedOutput<T> {
+        type Output = T;
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+            match self.get_mut().state.lock().unwrap().take() {
+                Some(t) => Poll::Ready(t),
+                None => Poll::Pending,
+            }
+        }
+    }
+
+    /// The `Arc`/`Mutex` twin of the crate root's private `FutureWrapper`.
+    struct SharedFutureWrapper<F: Future> {
+        future: F,
+        state: Arc<Mutex<Option<F::Output>>>,
+    }
+    impl<F: Future + Send> Future for SharedFutureWrapper<F> {
+        type Output = ();
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let future = unsafe {
+                Pin::new_unchecked(&mut Pin::get_unchecked_mut(self.as_mut()).future)
+            };
+            future.poll(cx).map(|out| {
+                *self.state.lock().unwrap() = Some(out);
+            })
+        }
+    }
+    impl<F> SharedFutureWrapper<F>
+    where
+        F: Future + Send,
+        F::Output: Send + Unpin,
+    {
+        fn new(f: F) -> (Self, SharedOutput<F::Output>) {
+            let output = SharedOutput::default();
+            (
+                Self {
+                    future: f,
+                    state: output.state.clone(),
+                },
+                output,
+            )
+        }
+    }
+
+    /// A child task wrapped so that, once it resolves, it wakes whoever is
+    /// polling the [`ParallelJoin`] it belongs to.
+    struct JoinedTask<F: Future> {
+        inner: SharedFutureWrapper<F>,
+        join_waker: Arc<Mutex<Option<Waker>>>,
+    }
+    impl<F: Future + Send> Future for JoinedTask<F> {
+        type Output = ();
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let this = unsafe { self.get_unchecked_mut() };
+            let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+            let result = inner.poll(cx);
+            let woken = result
+                .is_ready()
+                .then(|| this.join_waker.lock().unwrap().take())
+                .flatten();
+            if let Some(waker) = woken {
+                waker.wake();
+            }
+            result
+        }
+    }
+
+    /// Resolves to every branch's output once all of them have completed,
+    /// mirroring [`crate::Join`] for futures run on a [`ParallelPool`].
+    pub struct ParallelJoin<T> {
+        states: Vec<Arc<Mutex<Option<T>>>>,
+        join_waker: Arc<Mutex<Option<Waker>>>,
+    }
+    impl<T: Send + Unpin> Future for ParallelJoin<T> {
+        type Output = Vec<T>;
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Vec<T>> {
+            let this = self.get_mut();
+            // Register the waker *before* checking whether every child is
+            // done: checking first and registering after leaves a window
+            // where the last child can finish (and find nothing registered
+            // to wake) between the check and the store, dropping the
+            // wakeup forever. Registering first means any child that
+            // completes from this point on is guaranteed to see (and
+            // fire) this waker.
+            *this.join_waker.lock().unwrap() = Some(cx.waker().clone());
+            if this.states.iter().all(|state| state.lock().unwrap().is_some()) {
+                this.join_waker.lock().unwrap().take();
+                Poll::Ready(
+                    this.states
+                        .iter()
+                        .map(|state| state.lock().unwrap().take().unwrap())
+                        .collect(),
+                )
+            }
+            else {
+                Poll::Pending
+            }
+        }
+    }
+
+    type BoxedTask = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    // A task is queued at most once at a time: `IDLE` means it holds no
+    // queue entry, `NOTIFIED` means one is queued (or about to be), and
+    // `POLLING` means a worker currently owns it. Waking a `POLLING` task
+    // moves it to `REPOLL` instead of queuing a second entry, so the
+    // worker that's mid-poll is the one that re-queues it once it's done
+    // — otherwise a wake that lands while `future` is still checked out
+    // for polling would be silently dropped, hanging whatever is awaiting
+    // the task forever.
+    const IDLE: u8 = 0;
+    const NOTIFIED: u8 = 1;
+    const POLLING: u8 = 2;
+    const REPOLL: u8 = 3;
+    const COMPLETE: u8 = 4;
+
+    /// A task queued on a worker, re-queued onto its owning worker when
+    /// its waker fires so the worker picks it back up on its next pass.
+    struct TaskSlot {
+        future: Mutex<Option<BoxedTask>>,
+        state: AtomicU8,
+        worker: Arc<Worker>,
+        signal: Arc<(Mutex<bool>, Condvar)>,
+    }
+    impl TaskSlot {
+        /// Queue this task on its owning worker and wake a worker thread
+        /// to pick it up. Callers must only do this after winning the
+        /// `IDLE -> NOTIFIED` (or equivalent initial) transition, so a
+        /// task is never queued twice at once.
+        fn schedule(self: &Arc<Self>) {
+            self.worker.queue.lock().unwrap().push_back(self.clone());
+            let (lock, cvar) = &*self.signal;
+            *lock.lock().unwrap() = true;
+            cvar.notify_all();
+        }
+    }
+    impl Wake for TaskSlot {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+        fn wake_by_ref(self: &Arc<Self>) {
+            loop {
+                match self.state.load(Ordering::Acquire) {
+                    IDLE => {
+                        if self
+                            .state
+                            .compare_exchange(IDLE, NOTIFIED, Ordering::AcqRel, Ordering::Acquire)
+                            .is_ok()
+                        {
+                            self.schedule();
+                            return;
+                        }
+                    }
+                    POLLING => {
+                        if self
+                            .state
+                            .compare_exchange(
+                                POLLING,
+                                REPOLL,
+                                Ordering::AcqRel,
+                                Ordering::Acquire,
+                            )
+                            .is_ok()
+                        {
+                            return;
+                        }
+                    }
+                    // Already queued, already marked for a re-poll once
+                    // the in-flight poll finishes, or already done.
+                    NOTIFIED | REPOLL | COMPLETE => return,
+                    _ => unreachable!("invalid TaskSlot state"),
+                }
+            }
+        }
+    }
+
+    /// A single worker's task queue: tasks it owns are pushed onto the back
+    /// and popped from the front, and peers whose own queue is empty steal
+    /// from the back.
+    struct Worker {
+        queue: Mutex<VecDeque<Arc<TaskSlot>>>,
+    }
+
+    /// A small work-stealing thread pool dedicated to running the
+    /// independent branches of a `recurse_join` concurrently.
+    pub struct ParallelPool {
+        workers: Vec<Arc<Worker>>,
+        signal: Arc<(Mutex<bool>, Condvar)>,
+        shutdown: Arc<Mutex<bool>>,
+        handles: Vec<thread::JoinHandle<()>>,
+        next: Mutex<usize>,
+    }
+    impl ParallelPool {
+        pub fn new(worker_count: usize) -> Self {
+            let workers: Vec<_> = (0..worker_count.max(1))
+                .map(|_| {
+                    Arc::new(Worker {
+                        queue: Mutex::new(VecDeque::new()),
+                    })
+                })
+                .collect();
+            let signal = Arc::new((Mutex::new(false), Condvar::new()));
+            let shutdown = Arc::new(Mutex::new(false));
+
+            let handles = (0..workers.len())
+                .map(|id| {
+                    let workers = workers.clone();
+                    let signal = signal.clone();
+                    let shutdown = shutdown.clone();
+                    thread::spawn(move || worker_loop(id, workers, signal, shutdown))
+                })
+                .collect();
+
+            Self {
+                workers,
+                signal,
+                shutdown,
+                handles,
+                next: Mutex::new(0),
+            }
+        }
+
+        /// Schedule several independent child futures across the pool's
+        /// workers and await all of their results together.
+        pub fn recurse_join<F, I>(&self, futures: I) -> ParallelJoin<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + Unpin,
+            I: IntoIterator<Item = F>,
+        {
+            let join_waker = Arc::new(Mutex::new(None));
+            let states = futures
+                .into_iter()
+                .map(|f| {
+                    let (sfw, output) = SharedFutureWrapper::new(f);
+                    let task = JoinedTask {
+                        inner: sfw,
+                        join_waker: join_waker.clone(),
+                    };
+                    self.spawn(task);
+                    output.state
+                })
+                .collect();
+            ParallelJoin { states, join_waker }
+        }
+
+        fn spawn(&self, task: impl Future<Output = ()> + Send + 'static) {
+            let mut next = self.next.lock().unwrap();
+            let worker = self.workers[*next % self.workers.len()].clone();
+            *next += 1;
+            drop(next);
+
+            let slot = Arc::new(TaskSlot {
+                future: Mutex::new(Some(Box::pin(task))),
+                state: AtomicU8::new(NOTIFIED),
+                worker,
+                signal: self.signal.clone(),
+            });
+            slot.schedule();
+        }
+    }
+    impl Drop for ParallelPool {
+        fn drop(&mut self) {
+            *self.shutdown.lock().unwrap() = true;
+            let (lock, cvar) = &*self.signal;
+            *lock.lock().unwrap() = true;
+            cvar.notify_all();
+            for handle in self.handles.drain(..) {
+                let _ = handle.join();
+            }
+        }
+    }
+
+    fn worker_loop(
+        id: usize,
+        workers: Vec<Arc<Worker>>,
+        signal: Arc<(Mutex<bool>, Condvar)>,
+        shutdown: Arc<Mutex<bool>>,
+    ) {
+        loop {
+            // Each `pop_*` is its own statement so the `MutexGuard` is
+            // dropped immediately after: chaining `.or_else` directly
+            // onto the locked `pop_front()` would keep this worker's own
+            // queue locked for the whole steal attempt below, and every
+            // worker doing that at once is a lock-ordering deadlock.
+            let own = workers[id].queue.lock().unwrap().pop_front();
+            let task = own.or_else(|| {
+                workers
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != id)
+                    .find_map(|(_, worker)| worker.queue.lock().unwrap().pop_back())
+            });
+
+            let Some(slot) = task
+            else {
+                if *shutdown.lock().unwrap() {
+                    return;
+                }
+                let (lock, cvar) = &*signal;
+                let guard = lock.lock().unwrap();
+                let _ = cvar.wait_timeout(guard, Duration::from_millis(10));
+                continue;
+            };
+
+            // We just dequeued this slot, so it was `NOTIFIED`; claim it
+            // for polling before anyone else can queue it again.
+            slot.state.store(POLLING, Ordering::Release);
+
+            let taken = slot.future.lock().unwrap().take();
+            if let Some(mut future) = taken {
+                let waker = Waker::from(slot.clone());
+                let mut cx = Context::from_waker(&waker);
+                match future.as_mut().poll(&mut cx) {
+                    Poll::Ready(()) => {
+                        slot.state.store(COMPLETE, Ordering::Release);
+                    }
+                    Poll::Pending => {
+                        *slot.future.lock().unwrap() = Some(future);
+                        if slot
+                            .state
+                            .compare_exchange(
+                                POLLING,
+                                IDLE,
+                                Ordering::AcqRel,
+                                Ordering::Acquire,
+                            )
+                            .is_err()
+                        {
+                            // A wake landed while we were polling (state
+                            // moved to `REPOLL`): the wakeup would
+                            // otherwise be lost, so re-queue immediately
+                            // instead of going idle.
+                            slot.state.store(NOTIFIED, Ordering::Release);
+                            slot.schedule();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::ParallelPool;
+        use crate::FutureRecursion;
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::sync::Arc;
+
+        /// Recursive fib driven entirely through `ParallelPool::recurse_join`,
+        /// so every node below the root has a sibling scheduled onto another
+        /// worker concurrently. `fib(10)` spawns 176 tasks across 4 workers,
+        /// enough requeuing and cross-thread stealing to exercise a slot
+        /// being woken while its worker is still mid-poll.
+        fn fib(pool: Arc<ParallelPool>, n: u64) -> Pin<Box<dyn Future<Output = u64> + Send>> {
+            Box::pin(async move {
+                if n < 2 {
+                    return n;
+                }
+                let (left, right) = (pool.clone(), pool.clone());
+                let results = pool.recurse_join(vec![fib(left, n - 1), fib(right, n - 2)]).await;
+                results.into_iter().sum()
+            })
+        }
+
+        #[test]
+        fn recurse_join_survives_requeue_under_contention() {
+            let pool = Arc::new(ParallelPool::new(4));
+            let result = fib(pool, 10).start_recursion();
+            assert_eq!(result, 55);
+        }
+    }
+}
+
+impl<F> FutureRecursion for F
+where
+    F: Future + 'static,
+    F::Output: Unpin,
+{
+    fn start_recursion(self) -> Self::Output {
+        // `start_recursion` can be called reentrantly, e.g. from within a
+        // future that's itself being driven by an outer `start_recursion`
+        // call. The outer call holds `DEFAULT_RECURSION` borrowed for its
+        // whole (possibly long) blocking loop, so fall back to a fresh,
+        // one-off `Recursion` instead of panicking when it's already taken.
+        DEFAULT_RECURSION.with(|recursion| match recursion.try_borrow_mut() {
+            Ok(mut recursion) => recursion.run(self),
+            Err(_) => Recursion::new().run(self),
+        })
+    }
     fn recurse(self) -> Output<Self::Output> {
         let (fw, output) = FutureWrapper::new(self);
-        if RECURSION_TEM.replace(Some(Box::pin(fw))).is_some() {
-            panic!("incorrect recursion");
-        }
+        RECURSION_TEM.with(|tem| tem.borrow_mut().push(alloc_frame(fw)));
         output
     }
+    fn into_recursion_future(self) -> RecursionFuture<Self::Output> {
+        let (f, output) = FutureWrapper::new(self);
+        RecursionFuture {
+            stack: vec![alloc_frame(f)],
+            output,
+            budget: DEFAULT_STEP_BUDGET,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread;
+    use std::time::Duration;
+
+    /// A future that returns `Pending` once, spawns a real OS thread to
+    /// wake it after a short delay, and only resolves once that wake
+    /// fires -- so driving it exercises a genuine suspend/wake, not just a
+    /// cooperative yield.
+    struct WakeFromThread {
+        armed: bool,
+        fired: Arc<AtomicBool>,
+    }
+    impl Future for WakeFromThread {
+        type Output = u32;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            if self.armed {
+                if self.fired.load(Ordering::SeqCst) {
+                    return Poll::Ready(42);
+                }
+                return Poll::Pending;
+            }
+            self.armed = true;
+            let waker = cx.waker().clone();
+            let fired = self.fired.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                fired.store(true, Ordering::SeqCst);
+                waker.wake();
+            });
+            Poll::Pending
+        }
+    }
+
+    /// `start_recursion` parks the calling thread on a genuine suspension
+    /// (via the real park/unpark waker) and only returns once a wake from
+    /// another thread arrives, rather than busy-polling or hanging.
+    #[test]
+    fn start_recursion_blocks_on_genuine_suspension() {
+        async fn wait_and_add(n: u32) -> u32 {
+            n + WakeFromThread { armed: false, fired: Arc::default() }.await
+        }
+
+        let result = wait_and_add(8).start_recursion();
+        assert_eq!(result, 50);
+    }
+
+    /// `recurse_join` resolves every branch and collects their outputs
+    /// without panicking, instead of supporting only a single pending
+    /// child.
+    #[test]
+    fn recurse_join_resolves_all_children() {
+        async fn leaf(n: u32) -> u32 {
+            n * n
+        }
+        async fn branch() -> u32 {
+            recurse_join(vec![leaf(1), leaf(2), leaf(3), leaf(4)])
+                .await
+                .into_iter()
+                .sum()
+        }
+
+        let result = branch().start_recursion();
+        assert_eq!(result, 1 + 4 + 9 + 16);
+    }
+
+    /// A `Recursion`'s frames are recycled through the free list rather
+    /// than leaked: a second run of the same recursion shape should find
+    /// its frames already waiting in the free list instead of growing it
+    /// further.
+    #[test]
+    fn recursion_reuses_freed_frames() {
+        async fn depth(n: u32) -> u32 {
+            if n == 0 {
+                0
+            }
+            else {
+                1 + depth(n - 1).recurse().await
+            }
+        }
+
+        let before = FREE_LIST.with(|list| list.borrow().len());
+
+        let mut recursion = Recursion::with_capacity(8);
+        assert_eq!(recursion.run(depth(5)), 5);
+        let after_first = FREE_LIST.with(|list| list.borrow().len());
+        assert!(
+            after_first > before,
+            "completed frames should be recycled onto the free list"
+        );
+
+        assert_eq!(recursion.run(depth(5)), 5);
+        let after_second = FREE_LIST.with(|list| list.borrow().len());
+        assert_eq!(
+            after_second, after_first,
+            "a second run of the same shape should reuse freed frames, not grow the free list further"
+        );
+    }
+
+    struct CountingWaker(AtomicUsize);
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// A deep recursion driven through `into_recursion_future` with a
+    /// small budget must yield `Pending` (and re-arm the outer waker)
+    /// more than once instead of running to completion in a single poll.
+    #[test]
+    fn recursion_future_yields_within_budget() {
+        async fn count_down(n: u32) -> u32 {
+            if n == 0 {
+                0
+            }
+            else {
+                1 + count_down(n - 1).recurse().await
+            }
+        }
+
+        let mut fut = count_down(50).into_recursion_future().with_budget(4);
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut polls = 0;
+        let result = loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(v) => break v,
+                Poll::Pending => polls += 1,
+            }
+        };
+
+        assert_eq!(result, 50);
+        assert!(
+            polls > 1,
+            "a depth-50 recursion with a budget of 4 steps should need more than one outer poll"
+        );
+        assert!(
+            counter.0.load(Ordering::SeqCst) >= polls,
+            "each budget-exhausted yield should re-arm the outer waker"
+        );
+    }
+
+    /// The outer `Context`'s waker passed to `into_recursion_future`'s
+    /// `poll` is handed straight down to child futures, so a genuine
+    /// suspension inside the recursion wakes the *outer* task, not just
+    /// whatever waker the recursion machinery builds for itself.
+    #[test]
+    fn recursion_future_propagates_outer_waker_to_children() {
+        async fn wait_and_add(n: u32) -> u32 {
+            n + WakeFromThread { armed: false, fired: Arc::default() }.await
+        }
+
+        let mut fut = wait_and_add(8).into_recursion_future();
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(matches!(fut.as_mut().poll(&mut cx), Poll::Pending));
+        // Block until the spawned thread's `waker.wake()` increments the
+        // counter, proving it reached the outer waker we passed in above.
+        while counter.0.load(Ordering::SeqCst) == 0 {
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(result) => assert_eq!(result, 50),
+            Poll::Pending => panic!("recursion should resolve once its child has woken"),
+        }
+    }
 }